@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use num::{Float, Num};
+use num::{Float, Num, One, Zero};
 use std::iter::FromIterator;
 use std::ops::*;
 
@@ -14,172 +14,544 @@ where
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
-pub struct V3<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
-}
+pub struct Vector<T, const N: usize>(pub [T; N]);
 
-#[repr(C)]
-#[derive(Copy, Clone, Debug)]
-pub struct V4<T> {
-    pub x: T,
-    pub y: T,
-    pub w: T,
-}
+pub type V3<T> = Vector<T, 3>;
+pub type V4<T> = Vector<T, 4>;
 
-macro_rules! vector_default {
-    ($v:ident { $($field:ident),+ }) => {
-        impl<T: Default> Default for $v<T> {
-            fn default() -> Self { Self { $($field: T::default()),+ } }
+macro_rules! vector_accessors {
+    ($n:expr; $($i:expr => $name:ident),+) => {
+        impl<T: Copy> Vector<T, $n> {
+            $(pub fn $name(self) -> T {
+                self.0[$i]
+            })+
         }
     };
 }
 
-macro_rules! vector_from_into {
-    ($v:ident { $($field:ident),+ } $size:expr) => {
-        impl<'a, T: Copy> From<&'a [T; $size]> for $v<T> {
-            fn from(slice: &'a [T; $size]) -> Self {
-                let v: &V3<T> = From::from(slice);
-                *v
-            }
-        }
-        impl<'a, T> From<&'a [T; $size]> for &'a $v<T> {
-            fn from(slice: &'a [T; $size]) -> Self {
-                unsafe { std::mem::transmute(slice) }
+vector_accessors! { 3; 0 => x, 1 => y, 2 => z }
+vector_accessors! { 4; 0 => x, 1 => y, 2 => z, 3 => w }
+
+impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Self([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Self(array)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.0
+    }
+}
+
+impl<T: Default, const N: usize> FromIterator<T> for Vector<T, N> {
+    fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
+        let mut iter = iter.into_iter();
+        Self(std::array::from_fn(|_| iter.next().unwrap_or_default()))
+    }
+}
+
+impl<'a, T: 'a + Copy + Default, const N: usize> FromIterator<&'a T> for Vector<T, N> {
+    fn from_iter<U: IntoIterator<Item = &'a T>>(iter: U) -> Self {
+        let mut iter = iter.into_iter();
+        Self(std::array::from_fn(|_| *iter.next().unwrap_or(&T::default())))
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Vector<T, N> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Copy, const N: usize> IntoIterator for &Vector<T, N> {
+    type Item = T;
+    type IntoIter = std::array::IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Num + PartialOrd + Copy, const N: usize> PartialOrd for Vector<T, N> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        let self_mag_2 = (0..N).fold(T::zero(), |acc, i| acc + self.0[i] * self.0[i]);
+        let rhs_mag_2 = (0..N).fold(T::zero(), |acc, i| acc + rhs.0[i] * rhs.0[i]);
+        self_mag_2.partial_cmp(&rhs_mag_2)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Vector<T, N> {
+    fn eq(&self, rhs: &Self) -> bool {
+        (0..N).all(|i| self.0[i] == rhs.0[i])
+    }
+}
+
+macro_rules! vector_operator {
+    (unary $trt:ident::$func:ident) => {
+        impl<T: $trt<Output = T> + Copy, const N: usize> $trt for Vector<T, N> {
+            type Output = Self;
+            fn $func(self) -> Self::Output {
+                Self(std::array::from_fn(|i| $trt::$func(self.0[i])))
             }
         }
-        impl<'a, T> From<&'a mut [T; $size]> for &'a mut $v<T> {
-            fn from(slice: &'a mut [T; $size]) -> Self {
-                unsafe { std::mem::transmute(slice) }
+    };
+    (binary $trt:ident::$func:ident) => {
+        impl<T: $trt<Output = T> + Copy, const N: usize> $trt for Vector<T, N> {
+            type Output = Self;
+            fn $func(self, rhs: Self) -> Self::Output {
+                Self(std::array::from_fn(|i| $trt::$func(self.0[i], rhs.0[i])))
             }
         }
-        impl<'a, T: Copy> From<&'a $v<T>> for [T; $size] {
-            fn from(vector: &'a $v<T>) -> Self {
-                let s: &[T; 3] = From::from(vector);
-                *s
+        impl<T: $trt<Output = T> + Copy, const N: usize> $trt<T> for Vector<T, N> {
+            type Output = Self;
+            fn $func(self, rhs: T) -> Self::Output {
+                Self(std::array::from_fn(|i| $trt::$func(self.0[i], rhs)))
             }
         }
-        impl<'a, T> From<&'a $v<T>> for &'a [T; $size] {
-            fn from(vector: &'a $v<T>) -> Self {
-                unsafe { std::mem::transmute(vector) }
+    };
+    (assign $trt:ident::$func:ident) => {
+        impl<T: $trt + Copy, const N: usize> $trt for Vector<T, N> {
+            fn $func(&mut self, rhs: Self) {
+                for i in 0..N {
+                    $trt::$func(&mut self.0[i], rhs.0[i]);
+                }
             }
         }
-        impl<'a, T> From<&'a mut $v<T>> for &'a mut [T; $size] {
-            fn from(vector: &'a mut $v<T>) -> Self {
-                unsafe { std::mem::transmute(vector) }
+        impl<T: $trt + Copy, const N: usize> $trt<T> for Vector<T, N> {
+            fn $func(&mut self, rhs: T) {
+                for i in 0..N {
+                    $trt::$func(&mut self.0[i], rhs);
+                }
             }
         }
     };
 }
 
-macro_rules! vector_iterator {
-    (from $v:ident { $($field:ident),+ }) => {
-        impl<T: Default> FromIterator<T> for $v<T> {
-            fn from_iter<U: IntoIterator<Item = T>>(iter: U) -> Self {
-                let mut iter = iter.into_iter();
-                Self { $($field: iter.next().unwrap_or_default()),+ }
-            }
-        }
-        impl<'a, T: 'a + Copy + Default> FromIterator<&'a T> for $v<T> {
-            fn from_iter<U: IntoIterator<Item = &'a T>>(iter: U) -> Self {
-                let mut iter = iter.into_iter();
-                Self { $($field: *iter.next().unwrap_or(&T::default())),+ }
+vector_operator! { unary Not::not }
+vector_operator! { unary Neg::neg }
+vector_operator! { binary Add::add }
+vector_operator! { binary Sub::sub }
+vector_operator! { binary Mul::mul }
+vector_operator! { binary Div::div }
+vector_operator! { binary Rem::rem }
+vector_operator! { assign AddAssign::add_assign }
+vector_operator! { assign SubAssign::sub_assign }
+vector_operator! { assign MulAssign::mul_assign }
+vector_operator! { assign DivAssign::div_assign }
+vector_operator! { assign RemAssign::rem_assign }
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct M3<T>(pub [[T; 3]; 3]);
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct M4<T>(pub [[T; 4]; 4]);
+
+macro_rules! matrix_default {
+    ($m:ident, $n:expr) => {
+        impl<T: Num + Copy> Default for $m<T> {
+            fn default() -> Self {
+                let mut rows = [[T::zero(); $n]; $n];
+                for i in 0..$n {
+                    rows[i][i] = T::one();
+                }
+                Self(rows)
             }
         }
     };
-    (into $v:ident { $($field:ident),+ } $size:expr) => {
-        impl<T> IntoIterator for $v<T> {
-            type Item = T;
-            type IntoIter = std::array::IntoIter<T, $size>;
-            fn into_iter(self) -> Self::IntoIter {
-                std::array::IntoIter::new([ $(self.$field),* ])
-            }
-        }
-        impl<T: Copy> IntoIterator for &$v<T> {
-            type Item = T;
-            type IntoIter = std::array::IntoIter<T, $size>;
-            fn into_iter(self) -> Self::IntoIter {
-                std::array::IntoIter::new([ $(self.$field),* ])
+}
+
+macro_rules! matrix_mul {
+    ($m:ident, $n:expr) => {
+        impl<T: Num + Copy> Mul for $m<T> {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                let mut out = [[T::zero(); $n]; $n];
+                for i in 0..$n {
+                    for j in 0..$n {
+                        for k in 0..$n {
+                            out[i][j] = out[i][j] + self.0[i][k] * rhs.0[k][j];
+                        }
+                    }
+                }
+                Self(out)
             }
         }
-
     };
 }
 
-macro_rules! vector_comparator {
-    ($v:ident { $($field:ident),+ }) => {
-        impl<T: Float> PartialOrd for $v<T> {
-            fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
-                let self_mag_2 = $(self.$field * self.$field +)+ T::zero();
-                let rhs_mag_2 = $(rhs.$field * rhs.$field +)+ T::zero();
-                return match self_mag_2 - rhs_mag_2 {
-                    x if x < T::zero() => Some(std::cmp::Ordering::Less),
-                    x if x > T::zero() => Some(std::cmp::Ordering::Greater),
-                    _ => Some(std::cmp::Ordering::Equal),
-                };
-            }
-        }
-        impl<T: PartialEq> PartialEq for $v<T> {
-            fn eq(&self, rhs: &Self) -> bool {
-                $(self.$field == rhs.$field)&&+
+macro_rules! matrix_vector_mul {
+    ($m:ident, $v:ident, $n:expr) => {
+        impl<T: Num + Copy> Mul<$v<T>> for $m<T> {
+            type Output = $v<T>;
+            fn mul(self, rhs: $v<T>) -> Self::Output {
+                let mut out = [T::zero(); $n];
+                for i in 0..$n {
+                    for k in 0..$n {
+                        out[i] = out[i] + self.0[i][k] * rhs.0[k];
+                    }
+                }
+                Vector(out)
             }
         }
-
     };
 }
 
-macro_rules! vector_operator {
-    (unary $v:ident { $($field:ident),+ } $trt:ident::$func:ident) => {
-        impl<T: $trt<Output = T>> $trt for $v<T> {
-            type Output = Self;
-            fn $func(self) -> Self::Output {
-                Self::Output { $($field: $trt::$func(self.$field)),+ }
+macro_rules! matrix_ops {
+    ($m:ident, $n:expr) => {
+        impl<T: Num + Copy> $m<T> {
+            /// Identity matrix, the multiplicative identity for `Mul` and `pow`.
+            pub fn identity() -> Self {
+                Self::default()
             }
-        }
-    };
-    (binary $v:ident { $($field:ident),+ } $trt:ident::$func:ident) => {
-        impl<T: $trt<Output = T>> $trt for $v<T> {
-            type Output = Self;
-            fn $func(self, rhs: Self) -> Self::Output {
-                Self::Output { $($field: $trt::$func(self.$field, rhs.$field)),+ }
+
+            pub fn transpose(self) -> Self {
+                let mut out = [[T::zero(); $n]; $n];
+                for i in 0..$n {
+                    for j in 0..$n {
+                        out[j][i] = self.0[i][j];
+                    }
+                }
+                Self(out)
             }
-        }
-        impl<T: $trt<Output = T> + Copy> $trt<T> for $v<T> {
-            type Output = Self;
-            fn $func(self, rhs: T) -> Self::Output {
-                Self::Output { $($field: $trt::$func(self.$field, rhs)),+ }
+
+            /// Binary exponentiation, so applying a transform `n` times costs `O(log n)`
+            /// matrix multiplications instead of `O(n)`.
+            pub fn pow(self, mut n: u64) -> Self {
+                let mut result = Self::identity();
+                let mut base = self;
+                while n > 0 {
+                    if n & 1 == 1 {
+                        result = result * base;
+                    }
+                    base = base * base;
+                    n >>= 1;
+                }
+                result
             }
         }
     };
-    (assign $v:ident { $($field:ident),+ } $trt:ident::$func:ident) => {
-        impl<T: $trt> $trt for $v<T> {
-            fn $func(&mut self, rhs: Self) {
-                $($trt::$func(&mut self.$field, rhs.$field));*
-            }
+}
+
+matrix_default! { M3, 3 }
+matrix_mul! { M3, 3 }
+matrix_vector_mul! { M3, V3, 3 }
+matrix_ops! { M3, 3 }
+
+matrix_default! { M4, 4 }
+matrix_mul! { M4, 4 }
+matrix_vector_mul! { M4, V4, 4 }
+matrix_ops! { M4, 4 }
+
+/// The part of `Geometry` that only needs ring arithmetic (`Num`), so it also
+/// works for exact scalars like `Frac` that can't do `sqrt`.
+pub trait Dot<T: Num>: Sized + Copy {
+    fn dot(self, rhs: Self) -> T;
+    fn length_squared(self) -> T {
+        self.dot(self)
+    }
+}
+
+pub trait Geometry<T: Float>: Dot<T> {
+    fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+    fn normalize(self) -> Self;
+    fn distance(self, rhs: Self) -> T;
+    fn lerp(self, rhs: Self, t: T) -> Self;
+    fn reflect(self, normal: Self) -> Self;
+    fn project_onto(self, onto: Self) -> Self;
+}
+
+impl<T: Num + Copy, const N: usize> Dot<T> for Vector<T, N> {
+    fn dot(self, rhs: Self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.0[i] * rhs.0[i])
+    }
+}
+
+impl<T: Float, const N: usize> Geometry<T> for Vector<T, N> {
+    fn normalize(self) -> Self {
+        let len = self.length();
+        if len == T::zero() {
+            self
+        } else {
+            self / len
         }
-        impl<T: $trt + Copy> $trt<T> for $v<T> {
-            fn $func(&mut self, rhs: T) {
-                $($trt::$func(&mut self.$field, rhs));*
-            }
+    }
+    fn distance(self, rhs: Self) -> T {
+        (self - rhs).length()
+    }
+    fn lerp(self, rhs: Self, t: T) -> Self {
+        self + (rhs - self) * t
+    }
+    fn reflect(self, normal: Self) -> Self {
+        let d = self.dot(normal);
+        self - normal * (d + d)
+    }
+    fn project_onto(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.dot(onto))
+    }
+}
+
+impl<T: Num + Copy> Vector<T, 3> {
+    pub fn cross(self, rhs: Self) -> Self {
+        Self([
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        ])
+    }
+}
+
+/// Greatest common divisor, as a magnitude: works entirely in `u64` so reducing
+/// an `i64::MIN` numerator or denominator can never overflow on the final `abs`.
+fn gcd(a: i64, b: i64) -> u64 {
+    let mut a = a.unsigned_abs();
+    let mut b = b.unsigned_abs();
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// An exact rational scalar, so geometry over integer inputs (lattice points,
+/// exact intersection tests) doesn't lose precision the way `f32`/`f64` does.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct Frac {
+    pub numer: i64,
+    pub denom: i64,
+}
+
+impl Frac {
+    /// Builds a fraction in lowest terms with the sign normalized onto the numerator.
+    ///
+    /// Reduces the magnitudes by the gcd before reattaching the sign, so extreme inputs
+    /// like `new(i64::MIN, -2)` or `new(i64::MIN, i64::MIN)` shrink to a representable
+    /// value first instead of overflowing on a premature negation.
+    pub fn new(numer: i64, denom: i64) -> Self {
+        assert!(denom != 0, "attempt to divide by zero");
+        let g = gcd(numer, denom).max(1);
+        let numer_mag = numer.unsigned_abs() / g;
+        let denom_mag = denom.unsigned_abs() / g;
+        let to_i64 = |mag: u64| i64::try_from(mag).expect("Frac component overflowed i64");
+        let negative = (numer < 0) != (denom < 0);
+        let numer = if negative { -to_i64(numer_mag) } else { to_i64(numer_mag) };
+        Self { numer, denom: to_i64(denom_mag) }
+    }
+
+    pub fn reduced(self) -> Self {
+        Self::new(self.numer, self.denom)
+    }
+}
+
+impl Default for Frac {
+    fn default() -> Self {
+        Self { numer: 0, denom: 1 }
+    }
+}
+
+impl PartialEq for Frac {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.numer * rhs.denom == rhs.numer * self.denom
+    }
+}
+
+impl PartialOrd for Frac {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        // denom is always normalized positive by `new`, so cross-multiplying preserves order.
+        (self.numer * rhs.denom).partial_cmp(&(rhs.numer * self.denom))
+    }
+}
+
+impl Zero for Frac {
+    fn zero() -> Self {
+        Self { numer: 0, denom: 1 }
+    }
+    fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+}
+
+impl One for Frac {
+    fn one() -> Self {
+        Self { numer: 1, denom: 1 }
+    }
+}
+
+impl Add for Frac {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.numer * rhs.denom + rhs.numer * self.denom, self.denom * rhs.denom)
+    }
+}
+
+impl Sub for Frac {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.numer * rhs.denom - rhs.numer * self.denom, self.denom * rhs.denom)
+    }
+}
+
+impl Mul for Frac {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.numer * rhs.numer, self.denom * rhs.denom)
+    }
+}
+
+impl Div for Frac {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(rhs.numer != 0, "attempt to divide by zero");
+        Self::new(self.numer * rhs.denom, self.denom * rhs.numer)
+    }
+}
+
+impl Rem for Frac {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self / rhs;
+        let truncated = Self::new(quotient.numer / quotient.denom, 1);
+        self - truncated * rhs
+    }
+}
+
+impl Num for Frac {
+    type FromStrRadixErr = std::num::ParseIntError;
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        match str.split_once('/') {
+            Some((numer, denom)) => Ok(Self::new(
+                i64::from_str_radix(numer, radix)?,
+                i64::from_str_radix(denom, radix)?,
+            )),
+            None => Ok(Self::new(i64::from_str_radix(str, radix)?, 1)),
         }
-    };
+    }
 }
 
-vector_default! { V3 { x , y, z } }
-vector_from_into! { V3 { x , y, z } 3 }
-vector_iterator! { from V3 { x , y, z } }
-vector_iterator! { into V3 { x , y, z } 3 }
-vector_comparator! { V3 { x , y, z } }
-vector_operator! { unary V3 { x , y, z } Not::not }
-vector_operator! { unary V3 { x , y, z } Neg::neg }
-vector_operator! { binary V3 { x , y, z } Add::add }
-vector_operator! { binary V3 { x , y, z } Sub::sub }
-vector_operator! { binary V3 { x , y, z } Mul::mul }
-vector_operator! { binary V3 { x , y, z } Div::div }
-vector_operator! { binary V3 { x , y, z } Rem::rem }
-vector_operator! { assign V3 { x , y, z } AddAssign::add_assign }
-vector_operator! { assign V3 { x , y, z } SubAssign::sub_assign }
-vector_operator! { assign V3 { x , y, z } MulAssign::mul_assign }
-vector_operator! { assign V3 { x , y, z } DivAssign::div_assign }
-vector_operator! { assign V3 { x , y, z } RemAssign::rem_assign }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_pow_matches_repeated_multiplication() {
+        let m = M3([[1.0, 1.0, 0.0], [0.0, 1.0, 1.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(m.pow(0).0, M3::<f64>::identity().0);
+        assert_eq!(m.pow(1).0, m.0);
+        assert_eq!(m.pow(2).0, (m * m).0);
+        assert_eq!(m.pow(3).0, (m * m * m).0);
+
+        let m4 = M4([
+            [1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0, 1.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        assert_eq!(m4.pow(0).0, M4::<f64>::identity().0);
+        assert_eq!(m4.pow(1).0, m4.0);
+        assert_eq!(m4.pow(2).0, (m4 * m4).0);
+        assert_eq!(m4.pow(3).0, (m4 * m4 * m4).0);
+
+        let v = V4::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!((m4.pow(2) * v).0, (m4 * (m4 * v)).0);
+    }
+
+    #[test]
+    fn cross_of_basis_vectors_follows_right_hand_rule() {
+        let x = V3::from([1.0, 0.0, 0.0]);
+        let y = V3::from([0.0, 1.0, 0.0]);
+        let z = V3::from([0.0, 0.0, 1.0]);
+        assert_eq!(x.cross(y).0, z.0);
+        assert_eq!(y.cross(x).0, (-z).0);
+        assert_eq!(y.cross(z).0, x.0);
+        assert_eq!(z.cross(x).0, y.0);
+    }
+
+    #[test]
+    fn normalize_leaves_zero_vector_unchanged() {
+        let zero = V3::from([0.0, 0.0, 0.0]);
+        assert_eq!(zero.normalize().0, zero.0);
+    }
+
+    #[test]
+    fn normalize_scales_to_unit_length() {
+        let v = V3::from([3.0, 4.0, 0.0]);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(v.normalize().length(), 1.0);
+    }
+
+    #[test]
+    fn v4_supports_all_four_components_and_elementwise_ops() {
+        let a = V4::from([1.0, 2.0, 3.0, 4.0]);
+        let b = V4::from([4.0, 3.0, 2.0, 1.0]);
+        assert_eq!((a.x(), a.y(), a.z(), a.w()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!((a + b).0, [5.0, 5.0, 5.0, 5.0]);
+        assert_eq!((a * 2.0).0, [2.0, 4.0, 6.0, 8.0]);
+    }
+
+    #[test]
+    fn partial_ord_compares_by_magnitude() {
+        let short = V3::from([1.0, 0.0, 0.0]);
+        let long = V3::from([3.0, 4.0, 0.0]);
+        assert!(short < long);
+        assert!(long > short);
+        assert_eq!(short.partial_cmp(&V3::from([0.0, 1.0, 0.0])), Some(std::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn from_iter_and_into_iter_round_trip() {
+        let v: V3<i32> = [1, 2, 3].into_iter().collect();
+        let collected: Vec<i32> = v.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn frac_new_reduces_to_lowest_terms_and_normalizes_sign() {
+        assert_eq!(Frac::new(2, 4), Frac::new(1, 2));
+        assert_eq!(Frac::new(-1, -2), Frac::new(1, 2));
+        assert_eq!(Frac::new(1, -2), Frac::new(-1, 2));
+        let f = Frac::new(6, -9);
+        assert_eq!((f.numer, f.denom), (-2, 3));
+    }
+
+    #[test]
+    fn frac_arithmetic_is_exact() {
+        let a = Frac::new(1, 2);
+        let b = Frac::new(1, 3);
+        assert_eq!(a + b, Frac::new(5, 6));
+        assert_eq!(a - b, Frac::new(1, 6));
+        assert_eq!(a * b, Frac::new(1, 6));
+        assert_eq!(a / b, Frac::new(3, 2));
+        assert_eq!(Frac::new(7, 2) % Frac::new(1, 1), Frac::new(1, 2));
+    }
+
+    #[test]
+    fn frac_new_does_not_overflow_on_i64_min_numer() {
+        let f = Frac::new(i64::MIN, -2);
+        assert_eq!(f, Frac::new(1i64 << 62, 1));
+    }
+
+    #[test]
+    fn frac_new_does_not_overflow_when_gcd_reduces_to_i64_min() {
+        assert_eq!(Frac::new(i64::MIN, i64::MIN), Frac::new(1, 1));
+        assert_eq!(Frac::new(0, i64::MIN), Frac::new(0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn frac_new_rejects_zero_denominator() {
+        Frac::new(5, 0);
+    }
+}